@@ -0,0 +1,71 @@
+#import bevy_sprite::mesh2d_view_bindings
+#import bevy_sprite::mesh2d_bindings
+
+// NOTE: Bindings must come before functions that use them!
+#import bevy_sprite::mesh2d_functions
+
+// Must match FruitJuiciness in src/fruit_material.rs
+struct FruitJuiciness {
+    wobble: f32,
+    wobble_speed: f32,
+    shine: f32,
+    time: f32,
+};
+
+@group(1) @binding(0)
+var<uniform> juiciness: FruitJuiciness;
+@group(1) @binding(1)
+var texture: texture_2d<f32>;
+@group(1) @binding(2)
+var texture_sampler: sampler;
+
+struct Vertex {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    #import bevy_sprite::mesh2d_vertex_output
+};
+
+// Goes from -1 to 1 and back, at the fruit's own pace
+fn wobble_wave() -> f32 {
+    // All the fruits of a kind share the material, so the offset comes from where the fruit is.
+    // Fruits fly straight up, so only x stays the same for the whole flight
+    let phase = mesh.model[3].x * 0.1;
+    return sin(juiciness.time * juiciness.wobble_speed + phase);
+}
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    // Squash and stretch: when the fruit gets wider it gets shorter, like jelly
+    let wobble = wobble_wave() * juiciness.wobble;
+    let position = vertex.position * vec3<f32>(1.0 + wobble, 1.0 - wobble, 1.0);
+
+    var out: VertexOutput;
+    out.uv = vertex.uv;
+    out.world_position = mesh2d_position_local_to_world(mesh.model, vec4<f32>(position, 1.0));
+    out.clip_position = mesh2d_position_world_to_clip(out.world_position);
+    out.world_normal = mesh2d_normal_local_to_world(vertex.normal);
+    return out;
+}
+
+struct FragmentInput {
+    @builtin(front_facing) is_front: bool,
+    #import bevy_sprite::mesh2d_vertex_output
+};
+
+@fragment
+fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
+    let color = textureSample(texture, texture_sampler, in.uv);
+
+    // A soft highlight on the top left, that slides a bit with the wobble
+    // Like light on a wet surface
+    let highlight_center = vec2<f32>(0.35 + wobble_wave() * 0.02, 0.3);
+    let highlight = pow(max(1.0 - distance(in.uv, highlight_center) / 0.25, 0.0), 3.0) * juiciness.shine;
+
+    // Only shine where there is fruit
+    return vec4<f32>(color.rgb + vec3<f32>(highlight * color.a), color.a);
+}
@@ -0,0 +1,29 @@
+//! Checks that the numbers the code keeps about the assets still match the assets,
+//! so changing the art can't silently stretch things.
+
+use std::fs::File;
+use std::io::Read;
+use bevy::prelude::*;
+use crate::FRUITS;
+
+/// Reads the width and height from the header of a png in the assets folder
+fn png_size(path: &str) -> Vec2 {
+    let mut header = [0u8; 24];
+    File::open(format!("{}/assets/{}", env!("CARGO_MANIFEST_DIR"), path))
+        .and_then(|mut file| file.read_exact(&mut header))
+        .unwrap_or_else(|err| panic!("Can't read {}: {}", path, err));
+
+    // 8 bytes of signature, then the IHDR chunk's length and name, then the width and height
+    assert_eq!(&header[12..16], b"IHDR", "{} is not a png", path);
+    let width = u32::from_be_bytes([header[16], header[17], header[18], header[19]]);
+    let height = u32::from_be_bytes([header[20], header[21], header[22], header[23]]);
+
+    Vec2::new(width as f32, height as f32)
+}
+
+#[test]
+fn fruit_sizes_match_their_images() {
+    for kind in FRUITS.iter() {
+        assert_eq!(kind.size, png_size(kind.path), "The size of {} in FRUITS is not the image's", kind.path);
+    }
+}
@@ -1,9 +1,12 @@
 use bevy::prelude::*;
 use crate::PLAYER_GRAVITY;
+#[cfg(feature = "debug")]
+use bevy_inspector_egui::Inspectable;
 
 
 //region Movement
 #[derive(Component, Debug)]
+#[cfg_attr(feature = "debug", derive(Inspectable))]
 pub struct Velocity {
     pub x: f32,
     pub y: f32,
@@ -15,6 +18,7 @@ impl Default for Velocity {
 }
 
 #[derive(Component)]
+#[cfg_attr(feature = "debug", derive(Inspectable))]
 pub struct GravityAffects {
     pub strength: f32,
     pub dashing: bool, // When the player is dashing, gravity doesn't affect it
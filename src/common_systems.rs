@@ -6,11 +6,16 @@ use crate::player_plugin::{IsOnWall, Player};
 
 pub struct CommonSystems;
 
+/// Moves everything with its velocity.
+/// Systems that change velocities go before it, so the change shows up the same frame
+#[derive(SystemLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoveWithVelocity;
+
 impl Plugin for CommonSystems {
     fn build(&self, app: &mut App) {
         app
-            .add_system(move_with_velocity_system)
-            .add_system(gravity_system);
+            .add_system(move_with_velocity_system.label(MoveWithVelocity))
+            .add_system(gravity_system.before(MoveWithVelocity));
     }
 }
 
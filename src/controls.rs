@@ -1,10 +1,14 @@
 use crate::common_components::Aim;
-use crate::{KeyboardControls, MainCamera, TexturesHandles, AIM_SCALE, DASH_DURATION};
+use crate::{KeyboardControls, MainCamera, TexturesHandles, AIM_SCALE, DASH_DURATION, VIRTUAL_CURSOR_SPEED};
+use bevy::input::InputSystem;
 use bevy::prelude::*;
 use bevy::render::camera::RenderTarget;
+#[cfg(feature = "debug")]
+use bevy_inspector_egui::Inspectable;
 
 //region This resource defines the player's movements, defined by the keyboard/controller/mouse
 #[derive(Debug)]
+#[cfg_attr(feature = "debug", derive(Inspectable))]
 pub struct Movement {
     pub x: f32,
     pub jump: bool,
@@ -18,6 +22,15 @@ pub struct MouseCoordinates {
     pub y: f32,
 }
 
+/// The dash systems run in this order, or a dash can last a frame more or less
+#[derive(SystemLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DashSystems {
+    Direction,
+    CanDash,
+    Dash,
+}
+
+#[cfg_attr(feature = "debug", derive(Inspectable))]
 pub struct Dash {
     // Whether player clicked the dash button.
     // This may not result in a dash, for instance
@@ -38,11 +51,13 @@ pub struct Dash {
     pub direction: DashDirection,
 
     // Timer for the player dash
+    #[cfg_attr(feature = "debug", inspectable(ignore))]
     pub duration: Timer,
 }
 
 // The direction of a dash
 #[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Inspectable))]
 pub struct DashDirection {
     pub x: f32,
     pub y: f32,
@@ -97,6 +112,32 @@ impl Default for Movement {
     }
 }
 
+/// The cursor the gamepad moves, in window coordinates like [`Window::cursor_position`].
+/// The real cursor can't be moved on every platform (Wayland, the web...),
+/// So anything that needs the cursor (the aim, UI hit-testing...) should ask [`VirtualCursor::cursor_position`]
+pub struct VirtualCursor {
+    pub position: Vec2,
+    // Whether the gamepad moved the cursor since the mouse last did
+    pub active: bool,
+}
+
+impl VirtualCursor {
+    /// Where the cursor is in the window, whether the gamepad or the mouse is moving it
+    pub fn cursor_position(&self, wnd: &Window) -> Option<Vec2> {
+        if self.active {
+            Some(self.position)
+        } else {
+            wnd.cursor_position()
+        }
+    }
+}
+
+impl Default for VirtualCursor {
+    fn default() -> Self {
+        VirtualCursor { position: Vec2::ZERO, active: false }
+    }
+}
+
 impl Default for MouseCoordinates {
     fn default() -> Self {
         MouseCoordinates { x: 0.0, y: 0.0 }
@@ -113,8 +154,11 @@ impl Plugin for ControlsPlugin {
             .insert_resource(MouseCoordinates::default())
             .insert_resource(Dash::default())
             .add_system(cursor_system)
+            .insert_resource(VirtualCursor::default())
+            .add_system_to_stage(CoreStage::PreUpdate, virtual_cursor_system.after(InputSystem))
+            .add_system_to_stage(CoreStage::PreUpdate, virtual_cursor_click_system.after(InputSystem))
             .add_system(keyboard_controls_system)
-            .add_system(dash_direction_arrows);
+            .add_system(dash_direction_arrows.label(DashSystems::Direction));
     }
 }
 //endregion
@@ -152,6 +196,68 @@ fn joystick_control_system() {
     todo!()
 }
 
+/// Lets gamepad-only players use the cursor, by moving the [`VirtualCursor`] with the right stick.
+fn virtual_cursor_system(
+    wnds: Res<Windows>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut virtual_cursor: ResMut<VirtualCursor>,
+    time: Res<Time>,
+) {
+    let wnd = match wnds.get_primary() {
+        Some(wnd) => wnd,
+        None => return,
+    };
+
+    // If the mouse moved, it takes the cursor back
+    if cursor_moved.iter().count() > 0 {
+        virtual_cursor.active = false;
+    }
+
+    let window_size = Vec2::new(wnd.width(), wnd.height());
+
+    for gamepad in gamepads.iter() {
+        let axis = |axis_type| {
+            axes.get(GamepadAxis { gamepad: *gamepad, axis_type }).unwrap_or(0.)
+        };
+        let stick = Vec2::new(axis(GamepadAxisType::RightStickX), axis(GamepadAxisType::RightStickY));
+
+        if stick != Vec2::ZERO {
+            // Start from where the mouse was, or from the middle of the window if it's outside
+            if !virtual_cursor.active {
+                virtual_cursor.position = wnd.cursor_position().unwrap_or(window_size / 2.);
+                virtual_cursor.active = true;
+            }
+
+            virtual_cursor.position += stick * VIRTUAL_CURSOR_SPEED * time.delta_seconds();
+        }
+    }
+
+    // Every frame and not only when it moves, so it also stays inside when the window shrinks
+    if virtual_cursor.active {
+        virtual_cursor.position = virtual_cursor.position.clamp(Vec2::ZERO, window_size);
+    }
+}
+
+/// The A button clicks, wherever the [`VirtualCursor`] is
+fn virtual_cursor_click_system(
+    gamepads: Res<Gamepads>,
+    buttons: Res<Input<GamepadButton>>,
+    mut mouse_buttons: ResMut<Input<MouseButton>>,
+) {
+    for gamepad in gamepads.iter() {
+        let click = GamepadButton { gamepad: *gamepad, button_type: GamepadButtonType::South };
+        if buttons.just_pressed(click) {
+            mouse_buttons.press(MouseButton::Left);
+        }
+        if buttons.just_released(click) {
+            mouse_buttons.release(MouseButton::Left);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn cursor_system(
     mut commands: Commands,
     q_aim_entity: Query<Entity, With<Aim>>,
@@ -164,6 +270,7 @@ fn cursor_system(
     q_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
 
     mut mouse_res: ResMut<MouseCoordinates>,
+    virtual_cursor: Res<VirtualCursor>,
 ) {
     // get the camera info and transform
     // assuming there is exactly one main camera entity, so query::single() is OK
@@ -177,7 +284,7 @@ fn cursor_system(
     };
 
     // check if the cursor is inside the window and get its position
-    if let Some(screen_pos) = wnd.cursor_position() {
+    if let Some(screen_pos) = virtual_cursor.cursor_position(wnd) {
         // If there is no aim, spawn it
         if q_aim_entity.get_single().is_err() {
             commands
@@ -0,0 +1,231 @@
+use std::time::{Duration, Instant};
+use bevy::diagnostic::{Diagnostics, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::EguiContext;
+use bevy_inspector_egui::egui;
+use bevy_inspector_egui::{InspectorPlugin, RegisterInspectable, WorldInspectorPlugin};
+use crate::{DEBUG_REPORT_KEY, DEBUG_REPORT_INTERVAL, FRAME_BUDGET};
+use crate::common_components::{GravityAffects, Velocity};
+use crate::controls::{Dash, Movement};
+use crate::player_plugin::JumpOffWallSpeed;
+
+//region Plugin boilerplate
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_plugin(FrameTimeDiagnosticsPlugin)
+            .insert_resource(LatencyReport::default())
+            .add_system(toggle_report_system)
+            .add_system(update_report_system)
+            .add_system(report_window_system);
+
+        // Time every core stage, so we know which one eats the frame budget
+        for (index, stage) in STAGES.into_iter().enumerate() {
+            app
+                .add_system_to_stage(stage.clone(), stage_start_system.exclusive_system().at_start())
+                .add_system_to_stage(stage, stage_end_system(index).exclusive_system().at_end());
+        }
+
+        // Bevy reads the input events in PreUpdate, so this is the earliest
+        // Point where we can know that a key was pressed
+        app
+            .add_system_to_stage(CoreStage::PreUpdate, input_timestamp_system.exclusive_system().at_end())
+            .add_system_to_stage(CoreStage::Last, frame_end_system.exclusive_system().at_end());
+
+        // Lets you see and edit entities while the game runs
+        app
+            .add_plugin(WorldInspectorPlugin::new())
+            .register_inspectable::<Velocity>()
+            .register_inspectable::<GravityAffects>()
+            .register_inspectable::<JumpOffWallSpeed>()
+            // The resources are inserted by their own plugins
+            .add_plugin(InspectorPlugin::<Movement>::new_insert_manually())
+            .add_plugin(InspectorPlugin::<Dash>::new_insert_manually());
+    }
+}
+//endregion
+
+//region Debug Resources
+const STAGES: [CoreStage; 5] = [
+    CoreStage::First,
+    CoreStage::PreUpdate,
+    CoreStage::Update,
+    CoreStage::PostUpdate,
+    CoreStage::Last,
+];
+
+/// Input latency and frame time per core stage, shown in a window and updated every [`DEBUG_REPORT_INTERVAL`].
+/// Toggled with [`DEBUG_REPORT_KEY`], it measures nothing while disabled.
+/// Only the stages of the main schedule are timed, not single systems nor the rendering,
+/// So it tells you which stage eats the frame budget but not which system in it
+pub struct LatencyReport {
+    pub enabled: bool,
+    // Also write the numbers to the log, every DEBUG_REPORT_INTERVAL
+    pub log: bool,
+
+    // When the input of the current frame was read, if there was any
+    input_read_at: Option<Instant>,
+    // Time from the input being read to the end of the frame, one per input
+    latency_samples: Vec<Duration>,
+
+    // When the stage that is running right now started
+    stage_started_at: Option<Instant>,
+    // How long each of the STAGES took in the last frame
+    stage_durations: [Duration; STAGES.len()],
+
+    // What is shown, so the numbers stay still long enough to be read
+    latency: Option<f32>,
+    frame_time: f32,
+    shown_stage_durations: [Duration; STAGES.len()],
+
+    timer: Timer,
+}
+
+impl Default for LatencyReport {
+    fn default() -> Self {
+        LatencyReport {
+            enabled: false,
+            log: false,
+            input_read_at: None,
+            latency_samples: Vec::new(),
+            stage_started_at: None,
+            stage_durations: [Duration::ZERO; STAGES.len()],
+            latency: None,
+            frame_time: 0.,
+            shown_stage_durations: [Duration::ZERO; STAGES.len()],
+            timer: Timer::from_seconds(DEBUG_REPORT_INTERVAL, true),
+        }
+    }
+}
+
+impl LatencyReport {
+    fn latency_text(&self) -> String {
+        match self.latency {
+            Some(latency) => format!("Input latency: ~{:.1}ms", latency * 1000.),
+            None => "Input latency: press a key".to_string(),
+        }
+    }
+
+    fn frame_time_text(&self) -> String {
+        format!(
+            "Frame time: {:.1}ms / {:.1}ms budget ({:.0}%)",
+            self.frame_time * 1000.,
+            FRAME_BUDGET * 1000.,
+            self.frame_time / FRAME_BUDGET * 100.
+        )
+    }
+
+    fn stage_text(&self, index: usize) -> String {
+        let duration = self.shown_stage_durations[index].as_secs_f32();
+        format!(
+            "{:?}: {:.2}ms ({:.0}% of budget)",
+            STAGES[index],
+            duration * 1000.,
+            duration / FRAME_BUDGET * 100.
+        )
+    }
+}
+//endregion
+
+fn toggle_report_system(
+    kb: Res<Input<KeyCode>>,
+    mut report: ResMut<LatencyReport>,
+) {
+    if kb.just_pressed(DEBUG_REPORT_KEY) {
+        report.enabled = !report.enabled;
+        report.latency_samples.clear();
+        report.latency = None;
+        info!("Latency report {}", if report.enabled { "enabled" } else { "disabled" });
+    }
+}
+
+fn stage_start_system(mut report: ResMut<LatencyReport>) {
+    if report.enabled {
+        report.stage_started_at = Some(Instant::now());
+    }
+}
+
+fn stage_end_system(index: usize) -> impl FnMut(ResMut<LatencyReport>) {
+    move |mut report: ResMut<LatencyReport>| {
+        if let Some(started_at) = report.stage_started_at.take() {
+            report.stage_durations[index] = started_at.elapsed();
+        }
+    }
+}
+
+fn input_timestamp_system(
+    mut report: ResMut<LatencyReport>,
+    kb: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+) {
+    if !report.enabled {
+        return;
+    }
+
+    if kb.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some() {
+        report.input_read_at = Some(Instant::now());
+    }
+}
+
+fn frame_end_system(mut report: ResMut<LatencyReport>) {
+    if let Some(input_read_at) = report.input_read_at.take() {
+        report.latency_samples.push(input_read_at.elapsed());
+    }
+}
+
+fn update_report_system(
+    mut report: ResMut<LatencyReport>,
+    diagnostics: Res<Diagnostics>,
+    time: Res<Time>,
+) {
+    if !report.enabled || !report.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    report.frame_time = diagnostics
+        .get(FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|x| x.average())
+        .unwrap_or_default() as f32;
+    report.shown_stage_durations = report.stage_durations;
+
+    // Once the main schedule is done the frame still has to be rendered and presented,
+    // Which we can't time from here, so count it as a whole frame.
+    // That makes this an estimate, and most likely a pessimistic one.
+    // Without new inputs the last estimate stays on screen
+    if !report.latency_samples.is_empty() {
+        let total: Duration = report.latency_samples.iter().sum();
+        let average = total.as_secs_f32() / report.latency_samples.len() as f32;
+        report.latency = Some(average + report.frame_time);
+        report.latency_samples.clear();
+    }
+
+    if report.log {
+        info!("{}", report.latency_text());
+        info!("{}", report.frame_time_text());
+        for index in 0..STAGES.len() {
+            info!("  {}", report.stage_text(index));
+        }
+    }
+}
+
+fn report_window_system(
+    mut egui_context: ResMut<EguiContext>,
+    mut report: ResMut<LatencyReport>,
+) {
+    if !report.enabled {
+        return;
+    }
+
+    egui::Window::new("Latency report").show(egui_context.ctx_mut(), |ui| {
+        ui.label(report.latency_text());
+        ui.label(report.frame_time_text());
+        ui.separator();
+        for index in 0..STAGES.len() {
+            ui.label(report.stage_text(index));
+        }
+        ui.separator();
+        ui.checkbox(&mut report.log, "Also write to the log");
+    });
+}
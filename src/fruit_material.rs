@@ -0,0 +1,38 @@
+// ShaderType's derive makes a few functions that it never calls
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
+use bevy::sprite::Material2d;
+use crate::FRUIT_SHADER_PATH;
+
+/// The parameters of the fruit shader, one per kind of fruit.
+/// Must match `FruitJuiciness` in the shader
+#[derive(ShaderType, Clone, Debug)]
+pub struct FruitJuiciness {
+    pub wobble: f32,
+    pub wobble_speed: f32,
+    pub shine: f32,
+    // Seconds since startup wrapped to one wobble, updated every frame
+    pub time: f32,
+}
+
+#[derive(AsBindGroup, TypeUuid, Clone, Debug)]
+#[uuid = "5d0a9a4e-9f0c-4a8e-a3d6-2c1b7e4f8a61"]
+pub struct FruitMaterial {
+    #[uniform(0)]
+    pub juiciness: FruitJuiciness,
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Handle<Image>,
+}
+
+impl Material2d for FruitMaterial {
+    fn vertex_shader() -> ShaderRef {
+        FRUIT_SHADER_PATH.into()
+    }
+    fn fragment_shader() -> ShaderRef {
+        FRUIT_SHADER_PATH.into()
+    }
+}
@@ -1,7 +1,12 @@
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
-use crate::{FRUIT_SPEED, FRUITS_GRAVITY, FRUITS_SCALE, TexturesHandles,};
+use bevy::sprite::{Material2dPlugin, Mesh2dHandle};
+use crate::{FRUIT_SPEED, FRUITS_GRAVITY, FRUITS_SCALE, FRUITS, FruitShaderHandles, TexturesHandles,};
 use rand::{Rng, thread_rng};
+use std::f32::consts::TAU;
 use crate::common_components::{GravityAffects, Velocity};
+use crate::fruit_material::FruitMaterial;
+use crate::graphics::GraphicsQuality;
 
 //region Plugin Boilerplate
 pub struct FruitPlugin;
@@ -9,35 +14,121 @@ pub struct FruitPlugin;
 impl Plugin for FruitPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_startup_system_to_stage(StartupStage::PostStartup, spawn_fruit_system);
+            .add_plugin(Material2dPlugin::<FruitMaterial>::default())
+            .add_startup_system_to_stage(StartupStage::PostStartup, spawn_fruit_system)
+            .add_system(fruit_graphics_quality_system)
+            .add_system(fruit_material_time_system);
     }
 }
 //endregion
 
+//region Fruit Only Components
+#[derive(Component)]
+pub struct Fruit {
+    // Index of the fruit's kind in FRUITS
+    pub kind: usize,
+}
+//endregion
+
 fn spawn_fruit_system(
     mut commands: Commands,
     window: Res<Windows>,
-    textures: Res<TexturesHandles>
+    textures: Res<TexturesHandles>,
+    quality: Res<GraphicsQuality>,
+    shader_handles: Res<FruitShaderHandles>,
 ) {
     let number_of_fruits = textures.fruits.len();
     let index_of_fruit = thread_rng().gen_range(0..number_of_fruits);
-    let texture = textures.fruits[index_of_fruit].clone();
 
     let window = window.get_primary().unwrap();
     let y_spawn_position = -window.height() / 2. - 50.;
     let x_spawn_position = thread_rng().gen_range((-window.width() / 2.)..(window.width() / 2.));
 
-    commands.spawn_bundle(
-        SpriteBundle {
-            texture,
-            transform: Transform {
-                translation: Vec3::new(x_spawn_position, y_spawn_position, 0.0),
-                scale: FRUITS_SCALE,
-                ..Default::default()
-            },
+    let mut fruit = commands.spawn_bundle(
+        SpatialBundle::from_transform(Transform {
+            translation: Vec3::new(x_spawn_position, y_spawn_position, 0.0),
+            scale: FRUITS_SCALE,
             ..Default::default()
-        }
-    )
+        })
+    );
+    fruit
+        .insert(Fruit { kind: index_of_fruit })
         .insert(Velocity { x: 0., y: FRUIT_SPEED })
         .insert(GravityAffects { strength: FRUITS_GRAVITY, dashing: false, is_player: false });
-}
\ No newline at end of file
+
+    match *quality {
+        GraphicsQuality::High => insert_fruit_shader(&mut fruit, index_of_fruit, &shader_handles),
+        GraphicsQuality::Low => insert_fruit_sprite(&mut fruit, index_of_fruit, &textures),
+    }
+}
+
+/// Draws the fruit with the juicy [`FruitMaterial`]
+fn insert_fruit_shader(
+    fruit: &mut EntityCommands,
+    kind: usize,
+    shader_handles: &FruitShaderHandles,
+) {
+    fruit
+        // The quad is as big as the image, like a sprite, so both get the same size from the transform's scale
+        .insert(shader_handles.meshes[kind].clone())
+        .insert(shader_handles.materials[kind].clone());
+}
+
+/// Draws the fruit as a plain sprite, for low graphics
+fn insert_fruit_sprite(
+    fruit: &mut EntityCommands,
+    kind: usize,
+    textures: &TexturesHandles,
+) {
+    fruit
+        .insert(Sprite::default())
+        .insert(textures.fruits[kind].clone());
+}
+
+fn fruit_graphics_quality_system(
+    mut commands: Commands,
+    quality: Res<GraphicsQuality>,
+    query: Query<(Entity, &Fruit, Option<&Handle<FruitMaterial>>)>,
+    textures: Res<TexturesHandles>,
+    shader_handles: Res<FruitShaderHandles>,
+) {
+    if !quality.is_changed() {
+        return;
+    }
+
+    // Swap how the fruits that are already flying are drawn
+    for (entity, fruit, material) in query.iter() {
+        let mut entity = commands.entity(entity);
+
+        match (*quality, material) {
+            (GraphicsQuality::High, None) => {
+                entity
+                    .remove::<Sprite>()
+                    .remove::<Handle<Image>>();
+                insert_fruit_shader(&mut entity, fruit.kind, &shader_handles);
+            }
+            (GraphicsQuality::Low, Some(_)) => {
+                entity
+                    .remove::<Mesh2dHandle>()
+                    .remove::<Handle<FruitMaterial>>();
+                insert_fruit_sprite(&mut entity, fruit.kind, &textures);
+            }
+            _ => {} // Already drawn the right way
+        }
+    }
+}
+
+fn fruit_material_time_system(
+    shader_handles: Res<FruitShaderHandles>,
+    mut materials: ResMut<Assets<FruitMaterial>>,
+    time: Res<Time>,
+) {
+    for (kind, handle) in FRUITS.iter().zip(&shader_handles.materials) {
+        if let Some(material) = materials.get_mut(handle) {
+            // Wrapped to one wobble, so the f32 stays precise in long sessions
+            // And the wobble doesn't jump when it wraps
+            let wobble_period = (TAU / kind.wobble_speed) as f64;
+            material.juiciness.time = (time.seconds_since_startup() % wobble_period) as f32;
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Headless tests that play scripted inputs and check the player's trajectory,
+//! so changes to the movement and dash code can't silently change how the game feels.
+//! If one of these fails on purpose (you tuned the game), update the expected values.
+
+use std::time::{Duration, Instant};
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::{ButtonState, InputPlugin};
+use bevy::prelude::*;
+use bevy::window::{WindowDescriptor, WindowId};
+use raw_window_handle::{RawWindowHandle, WebHandle};
+use crate::common_components::MainCamera;
+use crate::player_plugin::Player;
+use crate::{common_systems, controls, player_plugin, TexturesHandles, PLAYER_SIZE};
+
+//region Harness
+const WINDOW_WIDTH: f32 = 1280.;
+const WINDOW_HEIGHT: f32 = 720.;
+// The game was tuned on a 60hz monitor, so that's what the tests run at
+const FRAME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+// Enough frames for the player to fall from the spawn point to the floor
+const FRAMES_TO_LAND: usize = 120;
+// Where the player is clamped to when on the floor.
+// Gravity keeps pulling while standing, so between frames the player can be a bit under it,
+// Which is why heights are measured from here and not from where the player stands
+const FLOOR: f32 = -WINDOW_HEIGHT / 2. + PLAYER_SIZE.y / 2.;
+
+/// The game without rendering, with a fake window and a clock that only moves when we say so.
+struct GameFeelHarness {
+    app: App,
+    now: Instant,
+}
+
+impl GameFeelHarness {
+    fn new() -> Self {
+        let mut app = App::new();
+
+        let mut windows = Windows::default();
+        windows.add(Window::new(
+            WindowId::primary(),
+            &WindowDescriptor::default(),
+            WINDOW_WIDTH as u32,
+            WINDOW_HEIGHT as u32,
+            1.,
+            None,
+            RawWindowHandle::Web(WebHandle::empty()),
+        ));
+
+        app
+            .add_plugin(InputPlugin)
+            .add_event::<CursorMoved>()
+            .insert_resource(windows)
+            .insert_resource(Time::default())
+            .insert_resource(TexturesHandles {
+                fruits: vec![],
+                ninja: Handle::default(),
+                aim: Handle::default(),
+            })
+            .add_plugin(common_systems::CommonSystems)
+            .add_plugin(controls::ControlsPlugin)
+            .add_plugin(player_plugin::PlayerPlugin);
+
+        app.world
+            .spawn()
+            .insert_bundle((Camera::default(), GlobalTransform::default(), MainCamera));
+
+        let mut harness = GameFeelHarness { app, now: Instant::now() };
+        // The first update runs the startup systems, which spawn the player
+        harness.step();
+        harness
+    }
+
+    fn step(&mut self) {
+        self.now += FRAME;
+        self.app.world.resource_mut::<Time>().update_with_instant(self.now);
+        self.app.update();
+    }
+
+    fn steps(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    fn send_key(&mut self, key_code: KeyCode, state: ButtonState) {
+        self.app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state,
+        });
+    }
+
+    fn press(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Pressed);
+    }
+
+    fn release(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Released);
+    }
+
+    /// Presses the key for a single frame
+    fn tap(&mut self, key_code: KeyCode) {
+        self.press(key_code);
+        self.step();
+        self.release(key_code);
+    }
+
+    fn player_position(&mut self) -> Vec2 {
+        self.app.world
+            .query_filtered::<&Transform, With<Player>>()
+            .single(&self.app.world)
+            .translation
+            .truncate()
+    }
+
+    /// Runs `frames` frames and returns the highest point the player reached
+    fn highest_point_during(&mut self, frames: usize) -> f32 {
+        let mut highest = self.player_position().y;
+        for _ in 0..frames {
+            self.step();
+            highest = highest.max(self.player_position().y);
+        }
+        highest
+    }
+}
+
+fn assert_close(actual: f32, expected: f32, tolerance: f32, what: &str) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "{} changed: expected {} ± {}, got {}",
+        what, expected, tolerance, actual
+    );
+}
+//endregion
+
+#[test]
+fn max_jump_height() {
+    let mut game = GameFeelHarness::new();
+    game.steps(FRAMES_TO_LAND);
+
+    game.tap(KeyCode::W);
+    let highest = game.highest_point_during(60);
+
+    // PLAYER_JUMP = 15 minus PLAYER_GRAVITY = 0.4 per frame
+    assert_close(highest - FLOOR, 273.8, 1., "Max jump height");
+}
+
+#[test]
+fn double_jump_goes_higher() {
+    let mut game = GameFeelHarness::new();
+    game.steps(FRAMES_TO_LAND);
+
+    game.tap(KeyCode::W);
+    game.steps(20);
+    game.tap(KeyCode::W);
+    let highest = game.highest_point_during(60);
+
+    assert_close(highest - FLOOR, 496.4, 1., "Double jump height");
+}
+
+#[test]
+fn horizontal_speed() {
+    let mut game = GameFeelHarness::new();
+    game.steps(FRAMES_TO_LAND);
+    let start = game.player_position().x;
+
+    game.press(KeyCode::D);
+    game.steps(30);
+    game.release(KeyCode::D);
+    game.step();
+
+    // PLAYER_SPEED = 10 pixels a frame
+    assert_close(game.player_position().x - start, 300., 1., "Horizontal speed");
+}
+
+#[test]
+fn dash_distance() {
+    let mut game = GameFeelHarness::new();
+    game.steps(FRAMES_TO_LAND);
+    let start = game.player_position().x;
+
+    game.tap(KeyCode::Right);
+    let highest = game.highest_point_during(30);
+
+    // DASH_SPEED = 50 pixels a frame for DASH_DURATION = 0.1 seconds.
+    // That's 7 frames and not 6, because 6 frames of 1/60s add up to a hair under 0.1s
+    assert_close(game.player_position().x - start, 350., 1., "Dash distance");
+    // A sideways dash doesn't lift the player
+    assert_close(highest, FLOOR, 0.01, "Sideways dash height");
+}
+
+#[test]
+fn second_dash_needs_a_wall() {
+    let mut game = GameFeelHarness::new();
+    game.steps(FRAMES_TO_LAND);
+    let start = game.player_position().x;
+
+    // MAX_PLAYER_DASHES_MIDAIR = 1 and the dash count only resets on a wall,
+    // So the second dash on the floor does nothing
+    game.tap(KeyCode::Right);
+    game.steps(30);
+    game.tap(KeyCode::Right);
+    game.steps(30);
+
+    assert_close(game.player_position().x - start, 350., 1., "Distance of two dashes");
+}
+
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use bevy::render::render_resource::WgpuAdapterInfo;
+use crate::{GRAPHICS_QUALITY_KEY, HIGH_QUALITY_MSAA_SAMPLES, LOW_QUALITY_MSAA_SAMPLES};
+
+//region Plugin boilerplate
+pub struct GraphicsPlugin;
+
+impl Plugin for GraphicsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_startup_system(choose_graphics_quality_system)
+            .add_system(toggle_graphics_quality_system)
+            .add_system(msaa_system);
+    }
+}
+//endregion
+
+//region Graphics Resources
+/// How pretty the game is.
+/// Low turns off the fruit shader and anti-aliasing,
+/// So integrated GPUs, software renderers and web builds can keep up with 60fps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsQuality {
+    High,
+    Low,
+}
+//endregion
+
+fn choose_graphics_quality_system(
+    mut commands: Commands,
+    adapter: Option<Res<WgpuAdapterInfo>>,
+) {
+    // Integrated GPUs, software renderers and the GL backend (WebGL and old drivers)
+    // Are the ones that struggle, start them on low
+    let quality = match adapter {
+        Some(adapter) if adapter.device_type != wgpu::DeviceType::IntegratedGpu
+            && adapter.device_type != wgpu::DeviceType::Cpu
+            && adapter.backend != wgpu::Backend::Gl => GraphicsQuality::High,
+        _ => GraphicsQuality::Low,
+    };
+    info!("Graphics quality: {:?}", quality);
+
+    commands.insert_resource(quality);
+}
+
+fn toggle_graphics_quality_system(
+    kb: Res<Input<KeyCode>>,
+    mut quality: ResMut<GraphicsQuality>,
+) {
+    if kb.just_pressed(GRAPHICS_QUALITY_KEY) {
+        *quality = match *quality {
+            GraphicsQuality::High => GraphicsQuality::Low,
+            GraphicsQuality::Low => GraphicsQuality::High,
+        };
+        info!("Graphics quality: {:?}", *quality);
+    }
+}
+
+fn msaa_system(
+    quality: Res<GraphicsQuality>,
+    mut msaa: ResMut<Msaa>,
+) {
+    if !quality.is_changed() {
+        return;
+    }
+
+    msaa.samples = match *quality {
+        GraphicsQuality::High => HIGH_QUALITY_MSAA_SAMPLES,
+        GraphicsQuality::Low => LOW_QUALITY_MSAA_SAMPLES,
+    };
+}
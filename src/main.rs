@@ -1,21 +1,34 @@
 use bevy::prelude::*;
+use bevy::sprite::Mesh2dHandle;
 use crate::common_components::MainCamera;
+use crate::fruit_material::{FruitJuiciness, FruitMaterial};
 
 //region Import Modules
 mod common_components;
 mod fruit_plugin;
+mod fruit_material;
+mod graphics;
 mod common_systems;
 mod controls;
 mod player_plugin;
+#[cfg(feature = "debug")]
+mod debug_plugin;
+#[cfg(test)]
+mod game_feel_tests;
+#[cfg(test)]
+mod asset_tests;
 //endregion
 
 //region Consts
 
 //region Assets
-const FRUIT_ASSETS_PATH: [&str; 2] = [
-    "watermelon.png",
-    "watermelon2.png",
+// Every kind of fruit, and how juicy it looks with the fruit shader.
+// `size` has to be the size of the png at `path`, asset_tests checks it
+const FRUITS: [FruitKind; 2] = [
+    FruitKind { path: "watermelon.png", size: Vec2::new(1000., 1000.), wobble: 0.04, wobble_speed: 5., shine: 0.35 },
+    FruitKind { path: "watermelon2.png", size: Vec2::new(1057., 938.), wobble: 0.06, wobble_speed: 4., shine: 0.25 },
 ];
+const FRUIT_SHADER_PATH: &str = "shaders/fruit.wgsl";
 const NINJA_PATH: &str = "ninja_xente.png";
 const AIM_PATH: &str = "aim.png";
 const FRUITS_SCALE: Vec3 = Vec3::new(0.1, 0.1, 1.0);
@@ -46,18 +59,49 @@ const JUMP_OFF_WALL_SPEED_ATTRITION: f32 = 5.;
 const DASH_DURATION: f32 = 0.1; // The duration of a dash in seconds
 const MAX_PLAYER_DASHES_MIDAIR: usize = 1;
 const DASH_SPEED: f32 = 50.;
+// Controls
+const VIRTUAL_CURSOR_SPEED: f32 = 800.; // How fast the gamepad moves the cursor, in pixels per second
+const GRAPHICS_QUALITY_KEY: KeyCode = KeyCode::F4; // Switches between high and low graphics
+// Graphics
+const HIGH_QUALITY_MSAA_SAMPLES: u32 = 4;
+const LOW_QUALITY_MSAA_SAMPLES: u32 = 1; // No anti-aliasing
 
 //endregion
 
+//region Debug Consts
+// Only used with `--features debug`
+#[cfg(feature = "debug")]
+const DEBUG_REPORT_KEY: KeyCode = KeyCode::F3;
+#[cfg(feature = "debug")]
+const DEBUG_REPORT_INTERVAL: f32 = 1.; // How often the latency report is updated, in seconds
+#[cfg(feature = "debug")]
+const FRAME_BUDGET: f32 = 1. / 60.; // The time a frame has to run at 60fps, in seconds
+//endregion
+
 //endregion
 
 //region Global structs definitions
+struct FruitKind {
+    path: &'static str,
+    size: Vec2, // Of the image at `path` in pixels, the fruit shader's quad needs it before the image is loaded
+    wobble: f32, // How much the fruit squashes and stretches, 0.1 is 10% of its size
+    wobble_speed: f32, // In radians per second
+    shine: f32, // How bright the highlight is, from 0 to 1
+}
+
 struct TexturesHandles {
     fruits: Vec<Handle<Image>>,
     ninja: Handle<Image>,
     aim: Handle<Image>,
 }
 
+// One of each per kind of fruit, shared by all the fruits of that kind,
+// So drawing more fruits doesn't make more buffers for the GPU
+struct FruitShaderHandles {
+    materials: Vec<Handle<FruitMaterial>>,
+    meshes: Vec<Mesh2dHandle>,
+}
+
 struct KeyboardControls {
     up: Vec<KeyCode>,
     down: Vec<KeyCode>,
@@ -81,28 +125,53 @@ impl KeyboardControls {
 //endregion
 
 fn main() {
-    App::new()
+    let mut app = App::new();
+    app
         .add_plugins(DefaultPlugins)
         .add_startup_system(setup_system)
+        .add_plugin(graphics::GraphicsPlugin)
         .add_plugin(fruit_plugin::FruitPlugin)
         .add_plugin(common_systems::CommonSystems)
         .add_plugin(controls::ControlsPlugin)
-        .add_plugin(player_plugin::PlayerPlugin)
-        .run();
+        .add_plugin(player_plugin::PlayerPlugin);
+
+    // Debug tools stay out of normal builds, run with `--features debug` to get them
+    #[cfg(feature = "debug")]
+    app.add_plugin(debug_plugin::DebugPlugin);
+
+    app.run();
 }
 
 fn setup_system(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<FruitMaterial>>,
     // window_res: Res<Windows>,
 ) {
     // Spawn camera
     commands.spawn_bundle(Camera2dBundle::default()).insert(MainCamera);
 
     //region Add asset handles
+    let fruits: Vec<Handle<Image>> = FRUITS.iter().map( |x| asset_server.load(x.path) ).collect();
+
+    commands.insert_resource(
+        FruitShaderHandles {
+            materials: FRUITS.iter().zip(&fruits).map( |(kind, texture)| materials.add(FruitMaterial {
+                juiciness: FruitJuiciness {
+                    wobble: kind.wobble,
+                    wobble_speed: kind.wobble_speed,
+                    shine: kind.shine,
+                    time: 0.,
+                },
+                texture: texture.clone(),
+            })).collect(),
+            meshes: FRUITS.iter().map( |x| Mesh2dHandle(meshes.add(Mesh::from(shape::Quad::new(x.size)))) ).collect(),
+        }
+    );
     commands.insert_resource(
        TexturesHandles {
-           fruits: FRUIT_ASSETS_PATH.iter().map( |x| asset_server.load(*x) ).collect(),
+           fruits,
            ninja: asset_server.load(NINJA_PATH),
            aim: asset_server.load(AIM_PATH),
        }
@@ -2,7 +2,10 @@ use bevy::ecs::schedule::ShouldRun;
 use bevy::prelude::*;
 use crate::{JUMP_OFF_WALL_SPEED_ATTRITION, MAX_PLAYER_JUMPS_MIDAIR, PLAYER_GRAVITY, PLAYER_FAST_FALLING_SPEED, PLAYER_GRAVITY_ON_WALL, PLAYER_HORIZONTAL_JUMP_WALL, PLAYER_JUMP, PLAYER_SCALE, PLAYER_SIZE, PLAYER_SPEED, PLAYER_VERTICAL_JUMP_WALL, TexturesHandles, MAX_PLAYER_DASHES_MIDAIR, DASH_DURATION, DASH_SPEED};
 use crate::common_components::{GravityAffects, Velocity};
-use crate::controls::{Dash, Movement, DashDirection};
+use crate::common_systems::MoveWithVelocity;
+use crate::controls::{Dash, Movement, DashDirection, DashSystems};
+#[cfg(feature = "debug")]
+use bevy_inspector_egui::Inspectable;
 
 //region Plugin boilerplate
 pub struct PlayerPlugin;
@@ -12,18 +15,29 @@ impl Plugin for PlayerPlugin {
         app
             .add_startup_system_to_stage(StartupStage::PostStartup, spawn_player_system)
             .add_system(player_corners_system)
+            // The movement systems go before the dash,
+            // Or they can overwrite the dash's velocity on the frame it starts
             .add_system_set(
                 SystemSet::new()
                     .with_run_criteria(movement_air_criteria)
                     .with_system(player_movement_air_system)
+                    .before(DashSystems::Dash)
+                    .before(MoveWithVelocity)
             )
         .add_system_set(
             SystemSet::new()
                 .with_run_criteria(movement_wall_criteria)
                 .with_system(player_movement_wall_system)
+                .before(DashSystems::Dash)
+                .before(MoveWithVelocity)
         )
-            .add_system(can_dash_system)
-            .add_system(dash_system);
+            .add_system(can_dash_system.label(DashSystems::CanDash).after(DashSystems::Direction))
+            .add_system(
+                dash_system
+                    .label(DashSystems::Dash)
+                    .after(DashSystems::CanDash)
+                    .before(MoveWithVelocity)
+            );
     }
 }
 //endregion
@@ -33,6 +47,7 @@ impl Plugin for PlayerPlugin {
 pub struct Player;
 
 #[derive(Component)]
+#[cfg_attr(feature = "debug", derive(Inspectable))]
 /// This is created in order to fix a bug where the player's speed is overwritten by other functions when on a wall.
 /// This is an additional speed that the player gains when jumping off a wall, added on top of the player's normal speed.
 /// The [`Movement`] struct is ONLY for the controls.